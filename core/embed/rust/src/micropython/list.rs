@@ -33,13 +33,115 @@ impl List {
         T: TryInto<Obj, Error = E>,
         Error: From<E>,
     {
-        let max_size = iter.size_hint().1.unwrap_or(0);
-        let mut gc_list = List::with_capacity(max_size)?;
+        let mut gc_list = List::with_capacity(0)?;
         let list = unsafe { Gc::as_mut(&mut gc_list) };
+        list.try_extend(iter)?;
+        Ok(gc_list)
+    }
+
+    /// Smallest capacity [`Self::reserve_one_amortized`] grows a full backing
+    /// array to; beyond it the capacity doubles, as in `mp_obj_list_append`
+    /// (py/objlist.c), so that single-element growth, like `append`'s, stays
+    /// amortized O(1) instead of reallocating the whole backing array on
+    /// every call.
+    const GROWTH_CHUNK: usize = 4;
+
+    /// Grows the backing array's capacity to exactly `capacity`, doing
+    /// nothing if it is already at least that large.
+    ///
+    /// This relies directly on `mp_obj_list_t`'s `alloc`/`items` fields
+    /// (py/obj.h) rather than going through a `mp_obj_list_*` accessor,
+    /// because there is no public API to grow a list's capacity without
+    /// also changing its length. The invariant pinned here, matching
+    /// `mp_obj_list_append`'s own growth in py/objlist.c, is: `items` is
+    /// either null (only possible when `alloc == 0`) or a GC allocation of
+    /// exactly `alloc` `Obj` slots, and `alloc` is only ever updated
+    /// together with the backing allocation, never independently of it. If
+    /// that struct layout or growth contract ever changes upstream, this is
+    /// the one place that needs to follow.
+    fn grow_to(&mut self, capacity: usize) -> Result<(), Error> {
+        if capacity <= self.alloc {
+            return Ok(());
+        }
+        unsafe {
+            // SAFETY: self is borrowed mutably, and `items` is either null or
+            // a valid allocation of `alloc` `Obj`s, as maintained by MicroPython.
+            // EXCEPTION: Will raise if allocation fails.
+            catch_exception(|| {
+                let new_items = ffi::gc_realloc(
+                    self.items.cast(),
+                    capacity * core::mem::size_of::<Obj>(),
+                    true,
+                );
+                if new_items.is_null() {
+                    ffi::m_malloc_fail(capacity * core::mem::size_of::<Obj>());
+                }
+                self.items = new_items.cast();
+                self.alloc = capacity;
+            })
+        }
+    }
+
+    /// Grows the backing array so that at least `additional` more elements
+    /// can be appended without reallocating again, sizing the allocation to
+    /// exactly `len + additional` with no extra slack. Meant for one-shot
+    /// bulk reservations (`try_extend`, `extend_from_slice`) where the final
+    /// size is already known; repeated single-element growth should use
+    /// [`Self::reserve_one_amortized`] instead, or it degrades to O(n^2).
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+        let required = self.len().saturating_add(additional);
+        self.grow_to(required)
+    }
+
+    /// Reserves room for one more element using the same amortized,
+    /// slack-adding growth as `mp_obj_list_append` (doubling the capacity, to
+    /// at least [`Self::GROWTH_CHUNK`], rather than growing to an exact size) instead of
+    /// [`Self::try_reserve`]'s exact sizing, so that calling this in a loop
+    /// (as `insert` does) is amortized O(1) per call rather than
+    /// reallocating and copying the whole array every time.
+    fn reserve_one_amortized(&mut self) -> Result<(), Error> {
+        if self.len() < self.alloc {
+            return Ok(());
+        }
+        self.grow_to(core::cmp::max(
+            self.alloc.saturating_mul(2),
+            Self::GROWTH_CHUNK,
+        ))
+    }
+
+    /// Reserves space for the iterator's `size_hint` up front (its upper
+    /// bound, or the lower bound if there is none), then adds each element
+    /// with [`Self::append`]. As long as the iterator yields no more elements
+    /// than were reserved, none of those appends reallocates; each one still
+    /// goes through `mp_obj_list_append`'s own capacity check.
+    pub fn try_extend<T, E>(&mut self, iter: impl Iterator<Item = T>) -> Result<(), Error>
+    where
+        T: TryInto<Obj, Error = E>,
+        Error: From<E>,
+    {
+        let (lower, upper) = iter.size_hint();
+        self.try_reserve(upper.unwrap_or(lower))?;
         for value in iter {
-            list.append(value.try_into()?)?;
+            self.append(value.try_into()?)?;
         }
-        Ok(gc_list)
+        Ok(())
+    }
+
+    /// Appends all of `items` in one bulk copy, instead of one
+    /// `append` call (and capacity check) per element.
+    pub fn extend_from_slice(&mut self, items: &[Obj]) -> Result<(), Error> {
+        self.try_reserve(items.len())?;
+        let len = self.len();
+        let items_ptr = self.as_mut_slice().as_mut_ptr();
+        unsafe {
+            // SAFETY: self is borrowed mutably, capacity for `items.len()`
+            // more elements was just reserved above, and `Obj` is a plain
+            // machine word with no `Drop`, so it is safe to copy in bulk.
+            ptr::copy_nonoverlapping(items.as_ptr(), items_ptr.add(len), items.len());
+            let list = self.as_mut_obj();
+            ffi::mp_obj_list_set_len(list, len + items.len());
+        }
+        Ok(())
     }
 
     // Internal helper to get the `Obj` variant of this.
@@ -92,6 +194,148 @@ impl List {
             core::slice::from_raw_parts_mut(items_ptr, len)
         }
     }
+
+    /// Inserts `value` at `index`, shifting everything after it one slot to
+    /// the right. Grows the backing array with the same amortized slack as
+    /// `append` if it is full, so in a loop the growth costs amortized O(1)
+    /// per call rather than reallocating the whole array every time; the
+    /// shift of the tail still costs O(len - index) per call.
+    pub fn insert(&mut self, index: usize, value: Obj) -> Result<(), Error> {
+        let len = self.len();
+        if index > len {
+            return Err(Error::IndexError);
+        }
+        self.reserve_one_amortized()?;
+        let items_ptr = self.as_mut_slice().as_mut_ptr();
+        unsafe {
+            // SAFETY: self is borrowed mutably, and capacity for one more
+            // element was just reserved above.
+            let tail = items_ptr.add(index);
+            ptr::copy(tail, tail.add(1), len - index);
+            ptr::write(tail, value);
+            let list = self.as_mut_obj();
+            ffi::mp_obj_list_set_len(list, len + 1);
+        }
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting everything after
+    /// it one slot to the left.
+    pub fn remove(&mut self, index: usize) -> Result<Obj, Error> {
+        let len = self.len();
+        if index >= len {
+            return Err(Error::IndexError);
+        }
+        let items_ptr = self.as_mut_slice().as_mut_ptr();
+        unsafe {
+            // SAFETY: self is borrowed mutably, and `index` is in bounds.
+            let removed_ptr = items_ptr.add(index);
+            let removed = ptr::read(removed_ptr);
+            ptr::copy(removed_ptr.add(1), removed_ptr, len - index - 1);
+            let list = self.as_mut_obj();
+            ffi::mp_obj_list_set_len(list, len - 1);
+            Ok(removed)
+        }
+    }
+
+    /// Removes and returns the last element, if any.
+    pub fn pop(&mut self) -> Option<Obj> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.remove(len - 1).ok()
+    }
+
+    /// Removes the element at `index`, replacing it with the last element.
+    /// This is O(1) but does not preserve ordering.
+    pub fn swap_remove(&mut self, index: usize) -> Result<Obj, Error> {
+        let len = self.len();
+        if index >= len {
+            return Err(Error::IndexError);
+        }
+        let slice = self.as_mut_slice();
+        slice.swap(index, len - 1);
+        self.remove(len - 1)
+    }
+
+    /// Shortens the list, keeping the first `len` elements and dropping the
+    /// rest. Does nothing if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len() {
+            return;
+        }
+        unsafe {
+            // SAFETY: self is borrowed mutably.
+            let list = self.as_mut_obj();
+            ffi::mp_obj_list_set_len(list, len);
+        }
+    }
+
+    /// Removes all elements from the list.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, compacting the
+    /// survivors toward the front in place without reallocating.
+    pub fn retain(&mut self, mut f: impl FnMut(Obj) -> bool) {
+        let slice = self.as_mut_slice();
+        let mut write = 0;
+        for read in 0..slice.len() {
+            if f(slice[read]) {
+                slice.swap(write, read);
+                write += 1;
+            }
+        }
+        self.truncate(write);
+    }
+
+    /// Removes the elements for which `f` returns `true`, compacting the
+    /// survivors in place, and returns the removed elements as a new list.
+    pub fn extract_if(
+        &mut self,
+        mut f: impl FnMut(Obj) -> Result<bool, Error>,
+    ) -> Result<Gc<List>, Error> {
+        let mut extracted = List::with_capacity(0)?;
+        let slice = self.as_mut_slice();
+        let len = slice.len();
+        let mut write = 0;
+        let mut read = 0;
+        let mut result = Ok(());
+        while read < len {
+            match f(slice[read]) {
+                Ok(true) => match unsafe { Gc::as_mut(&mut extracted) }.append(slice[read]) {
+                    Ok(()) => read += 1,
+                    Err(err) => {
+                        result = Err(err);
+                        break;
+                    }
+                },
+                Ok(false) => {
+                    slice.swap(write, read);
+                    write += 1;
+                    read += 1;
+                }
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            }
+        }
+        // If `f` or the append into `extracted` failed partway through, fold
+        // the element it failed on and everything still unprocessed back
+        // into the kept region instead of silently dropping it, so every
+        // original element still ends up in exactly one of `self` or
+        // `extracted`, never both and never neither.
+        if read < len {
+            slice.copy_within(read..len, write);
+            write += len - read;
+        }
+        self.truncate(write);
+        result?;
+        Ok(extracted)
+    }
 }
 
 impl From<Gc<List>> for Obj {
@@ -195,4 +439,215 @@ mod tests {
             assert_eq!(retrieved_vec[i], vec[i] + 10);
         }
     }
+
+    #[test]
+    fn list_try_extend() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 9> = (0..9).collect();
+        let mut list = List::with_capacity(0).unwrap();
+        unsafe { Gc::as_mut(&mut list) }
+            .try_extend(vec.iter().copied())
+            .unwrap();
+
+        let mut buf = IterBuf::new();
+        let iter = Iter::try_from_obj_with_buf(list.into(), &mut buf).unwrap();
+        let retrieved_vec: Vec<u16, 9> = iter
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<u16, 9>, Error>>()
+            .unwrap();
+        assert_eq!(vec, retrieved_vec);
+    }
+
+    #[test]
+    fn list_extend_from_slice() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 3> = (0..3).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        let extra: [Obj; 2] = [10u16.into(), 11u16.into()];
+        list.extend_from_slice(&extra).unwrap();
+
+        assert_eq!(list.len(), 5);
+        let values: Vec<u16, 5> = list
+            .as_slice()
+            .iter()
+            .map(|&o| o.try_into().unwrap())
+            .collect();
+        assert_eq!(values, [0, 1, 2, 10, 11]);
+    }
+
+    #[test]
+    fn list_insert_remove() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 5> = (0..5).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        list.insert(2, 99u16.into()).unwrap();
+        assert_eq!(list.len(), 6);
+        let slice = list.as_slice();
+        let values: Vec<u16, 6> = slice.iter().map(|&o| o.try_into().unwrap()).collect();
+        assert_eq!(values, [0, 1, 99, 2, 3, 4]);
+
+        let removed: u16 = list.remove(2).unwrap().try_into().unwrap();
+        assert_eq!(removed, 99);
+        assert_eq!(list.len(), 5);
+
+        assert!(matches!(list.remove(5), Err(Error::IndexError)));
+    }
+
+    #[test]
+    fn list_insert_grows_with_slack() {
+        unsafe { mpy_init() };
+
+        // `from_iter` reserves exactly the iterator's length, so a list longer
+        // than MicroPython's minimum allocation of four slots is left tightly
+        // packed (alloc == len).
+        let vec: Vec<u16, 5> = (0..5).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+        assert_eq!(list.alloc, list.len());
+
+        list.insert(0, 0u16.into()).unwrap();
+        // Growing by one element while full must add slack, not reallocate
+        // to the exact new length, or every repeated insert would reallocate
+        // the whole array.
+        assert!(list.alloc > list.len());
+
+        let alloc_after_first_grow = list.alloc;
+        while list.len() < alloc_after_first_grow {
+            list.insert(0, 0u16.into()).unwrap();
+        }
+        // Filling up the slack must not have reallocated again.
+        assert_eq!(list.alloc, alloc_after_first_grow);
+    }
+
+    #[test]
+    fn list_pop_swap_remove() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 5> = (0..5).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        let popped: u16 = list.pop().unwrap().try_into().unwrap();
+        assert_eq!(popped, 4);
+        assert_eq!(list.len(), 4);
+
+        let swapped: u16 = list.swap_remove(0).unwrap().try_into().unwrap();
+        assert_eq!(swapped, 0);
+        assert_eq!(list.len(), 3);
+        let slice = list.as_slice();
+        let values: Vec<u16, 3> = slice.iter().map(|&o| o.try_into().unwrap()).collect();
+        assert_eq!(values, [3, 1, 2]);
+    }
+
+    #[test]
+    fn list_truncate_clear() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 5> = (0..5).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        list.truncate(3);
+        assert_eq!(list.len(), 3);
+        list.truncate(10);
+        assert_eq!(list.len(), 3);
+
+        list.clear();
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn list_retain() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 6> = (0..6).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        list.retain(|o| {
+            let value: u16 = o.try_into().unwrap();
+            value % 2 == 0
+        });
+
+        assert_eq!(list.len(), 3);
+        let values: Vec<u16, 3> = list
+            .as_slice()
+            .iter()
+            .map(|&o| o.try_into().unwrap())
+            .collect();
+        assert_eq!(values, [0, 2, 4]);
+    }
+
+    #[test]
+    fn list_extract_if() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 6> = (0..6).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        let extracted = list
+            .extract_if(|o| {
+                let value: u16 = o.try_into().unwrap();
+                Ok(value % 2 == 0)
+            })
+            .unwrap();
+
+        assert_eq!(list.len(), 3);
+        let remaining: Vec<u16, 3> = list
+            .as_slice()
+            .iter()
+            .map(|&o| o.try_into().unwrap())
+            .collect();
+        assert_eq!(remaining, [1, 3, 5]);
+
+        assert_eq!(extracted.len(), 3);
+        let removed: Vec<u16, 3> = extracted
+            .as_slice()
+            .iter()
+            .map(|&o| o.try_into().unwrap())
+            .collect();
+        assert_eq!(removed, [0, 2, 4]);
+    }
+
+    #[test]
+    fn list_extract_if_error_partway() {
+        unsafe { mpy_init() };
+
+        let vec: Vec<u16, 5> = (0..5).collect();
+        let mut list = List::from_iter(vec.iter().copied()).unwrap();
+        let list = unsafe { Gc::as_mut(&mut list) };
+
+        // Extracts even values, but errors out once it reaches `3`, which is
+        // itself kept (the predicate never got to classify it).
+        let err = list
+            .extract_if(|o| {
+                let value: u16 = o.try_into().unwrap();
+                if value == 3 {
+                    Err(Error::IndexError)
+                } else {
+                    Ok(value % 2 == 0)
+                }
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::IndexError));
+
+        // `0` and `2` were already extracted before the error; `1`, `3` and
+        // the unvisited `4` must all still be present in `list` - no element
+        // was dropped or duplicated across the two lists.
+        let mut remaining: Vec<u16, 5> = list
+            .as_slice()
+            .iter()
+            .map(|&o| o.try_into().unwrap())
+            .collect();
+        remaining.sort_unstable();
+        assert_eq!(remaining, [1, 3, 4]);
+    }
 }